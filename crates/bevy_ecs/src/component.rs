@@ -0,0 +1,432 @@
+//! Types for declaring and storing a [`World`](crate::world::World)'s component metadata.
+
+use crate::{entity::Entity, world::DeferredWorld};
+use std::{any::TypeId, collections::HashMap, sync::Arc};
+
+/// A marker trait for a [`Component`]'s storage strategy. See [`TableStorage`] and
+/// [`SparseStorage`].
+pub trait StorageType: sealed::Sealed {}
+
+/// Store the component contiguously in a table, alongside every other component of entities
+/// that share the same archetype. Fast to iterate, slower to insert/remove.
+pub struct TableStorage;
+
+/// Store the component in a sparse set keyed by entity. Slower to iterate, fast to insert/remove.
+/// Useful for components that are added and removed frequently.
+pub struct SparseStorage;
+
+impl StorageType for TableStorage {}
+impl StorageType for SparseStorage {}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::TableStorage {}
+    impl Sealed for super::SparseStorage {}
+}
+
+/// A data type that can be inserted onto entities, and that can be looked up by its declared
+/// [`StorageType`].
+pub trait Component: Send + Sync + 'static {
+    /// How this component's instances are stored by the [`World`](crate::world::World).
+    type Storage: StorageType;
+
+    /// Declares hooks that should apply to this component from the moment it's registered,
+    /// without requiring an app to call [`register_component_hooks`] at startup. This is the
+    /// place to put lifecycle behavior that should ship with the type itself (e.g. an invariant
+    /// a library author wants to guarantee for every instance of this component in every app).
+    ///
+    /// [`register_component_hooks`]: crate::world::World::register_component_hooks
+    #[allow(unused_variables)]
+    fn init_component_info(info: &mut ComponentInfo) {}
+}
+
+/// A unique, densely packed identifier for a [`Component`] within a single `World`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct ComponentId(usize);
+
+impl ComponentId {
+    /// Creates a new `ComponentId`. Only [`Components`] should call this, as the id must line up
+    /// with the component's storage location.
+    #[inline]
+    pub(crate) const fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    /// Returns the index of this component within its `World`'s [`Components`].
+    #[inline]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A single component lifecycle hook. Stored as an `Arc` (rather than a bare fn pointer) so the
+/// same hook can be shared between a live [`ComponentHooks`] and a [`ReflectComponentHooks`]
+/// built from reflection, both of which may need to run it independently of the other.
+///
+/// [`ReflectComponentHooks`]: crate::reflect::ReflectComponentHooks
+pub(crate) type ComponentHook = Arc<dyn Fn(DeferredWorld, Entity, ComponentId) + Send + Sync>;
+
+/// The lifecycle hooks declared for a single component, installed either imperatively through
+/// [`register_component_hooks`](crate::world::World::register_component_hooks) or declaratively
+/// through [`Component::init_component_info`].
+///
+/// A component may only have one hook of each kind. This is enforced here (rather than silently
+/// overwriting) so that a baked-in hook from `init_component_info`, or a hook registered by a
+/// plugin, can never be clobbered by a later, unrelated call.
+#[derive(Default, Clone)]
+pub struct ComponentHooks {
+    pub(crate) on_add: Option<ComponentHook>,
+    pub(crate) on_insert: Option<ComponentHook>,
+    pub(crate) on_replace: Option<ComponentHook>,
+    pub(crate) on_remove: Option<ComponentHook>,
+}
+
+impl ComponentHooks {
+    /// Register a hook that will be run when this component is added to an entity that didn't
+    /// already have it.
+    pub fn on_add(
+        &mut self,
+        hook: impl Fn(DeferredWorld, Entity, ComponentId) + Send + Sync + 'static,
+    ) -> &mut Self {
+        Self::set_hook(&mut self.on_add, hook, "on_add");
+        self
+    }
+
+    /// Register a hook that will be run just before an existing value of this component is
+    /// overwritten by an insert, giving the hook a last chance to read the outgoing value before
+    /// it's dropped (for example, to remove it from an index built in `on_add`). Runs on the
+    /// insert codepath, between the old value being read and the new one taking its place, in
+    /// the order `on_replace` (old value) → value swap → `on_insert` (new value).
+    pub fn on_replace(
+        &mut self,
+        hook: impl Fn(DeferredWorld, Entity, ComponentId) + Send + Sync + 'static,
+    ) -> &mut Self {
+        Self::set_hook(&mut self.on_replace, hook, "on_replace");
+        self
+    }
+
+    /// Register a hook that will be run whenever this component is inserted onto an entity,
+    /// regardless of whether it already had it, after `on_add`/`on_replace` (whichever applies).
+    pub fn on_insert(
+        &mut self,
+        hook: impl Fn(DeferredWorld, Entity, ComponentId) + Send + Sync + 'static,
+    ) -> &mut Self {
+        Self::set_hook(&mut self.on_insert, hook, "on_insert");
+        self
+    }
+
+    /// Register a hook that will be run just before this component is removed from an entity.
+    pub fn on_remove(
+        &mut self,
+        hook: impl Fn(DeferredWorld, Entity, ComponentId) + Send + Sync + 'static,
+    ) -> &mut Self {
+        Self::set_hook(&mut self.on_remove, hook, "on_remove");
+        self
+    }
+
+    fn set_hook(
+        slot: &mut Option<ComponentHook>,
+        hook: impl Fn(DeferredWorld, Entity, ComponentId) + Send + Sync + 'static,
+        kind: &'static str,
+    ) {
+        assert!(
+            slot.is_none(),
+            "a component may only have one `{kind}` hook; this one was already set, either by a \
+            baked-in `Component::init_component_info` hook or by an earlier call to \
+            `register_component_hooks`"
+        );
+        *slot = Some(Arc::new(hook));
+    }
+
+    /// Builds the `ComponentHooks` declared by `C::init_component_info`, in isolation from any
+    /// live `World`. Used by [`ReflectComponentHooks`](crate::reflect::ReflectComponentHooks) to
+    /// reconstruct the same hooks from a `TypeRegistration` alone; [`Components::register_component`]
+    /// runs `init_component_info` directly on the component's own [`ComponentInfo`] instead.
+    pub(crate) fn from_declared<C: Component>() -> Self {
+        let mut info = ComponentInfo::new(ComponentId::new(0));
+        C::init_component_info(&mut info);
+        info.hooks
+    }
+
+    /// Copies over any hook from `other` whose slot isn't already set on `self`. Used to apply
+    /// the hooks declared via [`Component::init_component_info`] (or reconstructed from
+    /// [`ReflectComponentHooks`](crate::reflect::ReflectComponentHooks)) without overriding hooks
+    /// that are already in place.
+    pub(crate) fn merge_missing_from(&mut self, other: &ComponentHooks) {
+        if self.on_add.is_none() {
+            self.on_add = other.on_add.clone();
+        }
+        if self.on_replace.is_none() {
+            self.on_replace = other.on_replace.clone();
+        }
+        if self.on_insert.is_none() {
+            self.on_insert = other.on_insert.clone();
+        }
+        if self.on_remove.is_none() {
+            self.on_remove = other.on_remove.clone();
+        }
+    }
+
+    /// Runs `on_add` if it's set. The insert codepath calls this instead of `run_on_replace` when
+    /// the entity didn't already have the component, after the value is written (so the hook can
+    /// read it).
+    pub(crate) fn run_on_add(&self, world: DeferredWorld, entity: Entity, component_id: ComponentId) {
+        if let Some(hook) = &self.on_add {
+            hook(world, entity, component_id);
+        }
+    }
+
+    /// Runs `on_replace` if it's set. The insert codepath calls this instead of `run_on_add` when
+    /// the entity already had the component, while the outgoing value is still readable — the
+    /// caller must swap in the new value only *after* this returns, and call `run_on_insert`
+    /// only *after* that swap, to realize the `on_replace` (old) → swap → `on_insert` (new)
+    /// ordering `on_replace` documents.
+    pub(crate) fn run_on_replace(
+        &self,
+        world: DeferredWorld,
+        entity: Entity,
+        component_id: ComponentId,
+    ) {
+        if let Some(hook) = &self.on_replace {
+            hook(world, entity, component_id);
+        }
+    }
+
+    /// Runs `on_insert` if it's set. The insert codepath calls this last, after the value has
+    /// been written and after whichever of `run_on_add`/`run_on_replace` applied.
+    pub(crate) fn run_on_insert(&self, world: DeferredWorld, entity: Entity, component_id: ComponentId) {
+        if let Some(hook) = &self.on_insert {
+            hook(world, entity, component_id);
+        }
+    }
+}
+
+/// Metadata about a registered [`Component`], including its declared lifecycle hooks.
+pub struct ComponentInfo {
+    id: ComponentId,
+    hooks: ComponentHooks,
+}
+
+impl ComponentInfo {
+    pub(crate) fn new(id: ComponentId) -> Self {
+        Self {
+            id,
+            hooks: ComponentHooks::default(),
+        }
+    }
+
+    /// The [`ComponentId`] this info describes.
+    pub fn id(&self) -> ComponentId {
+        self.id
+    }
+
+    /// The hooks currently declared for this component.
+    pub fn hooks(&self) -> &ComponentHooks {
+        &self.hooks
+    }
+
+    /// Mutable access to this component's hooks, for `Component::init_component_info` to
+    /// install baked-in hooks into.
+    pub fn hooks_mut(&mut self) -> &mut ComponentHooks {
+        &mut self.hooks
+    }
+}
+
+/// The registry of every [`Component`] type a `World` knows about.
+///
+/// A component is only assigned a [`ComponentId`] the first time it's registered, and every
+/// archetype that can contain it is created afterwards (archetypes are built from already-known
+/// `ComponentId`s, never the other way around). That ordering is what makes `register_component`
+/// the right place to run `Component::init_component_info`: the baked-in hooks it installs are
+/// guaranteed to already be in place before the first archetype containing the component exists,
+/// so there's no window where an entity could have the component without them applying.
+#[derive(Default)]
+pub struct Components {
+    indices: HashMap<TypeId, ComponentId>,
+    infos: Vec<ComponentInfo>,
+}
+
+impl Components {
+    /// Returns the [`ComponentId`] for `T`, registering it (and running its baked-in
+    /// `init_component_info` hooks, if this is the first time) if it isn't already known.
+    pub fn register_component<T: Component>(&mut self) -> ComponentId {
+        if let Some(id) = self.indices.get(&TypeId::of::<T>()) {
+            return *id;
+        }
+
+        let id = ComponentId::new(self.infos.len());
+        let mut info = ComponentInfo::new(id);
+        T::init_component_info(&mut info);
+        self.infos.push(info);
+        self.indices.insert(TypeId::of::<T>(), id);
+        id
+    }
+
+    /// The [`ComponentInfo`] for an already-registered component.
+    pub fn get_info(&self, id: ComponentId) -> Option<&ComponentInfo> {
+        self.infos.get(id.index())
+    }
+
+    /// App-level access to a component's hooks, for composing additional behavior on top of
+    /// whatever `Component::init_component_info` already baked in.
+    ///
+    /// Registers `T` first if necessary, so any baked-in hooks are always set before this
+    /// returns. Hook kinds that are already set (by a baked-in hook, or by an earlier call to
+    /// this method from another plugin) are left alone by callers using [`ComponentHooks`]'s
+    /// `on_*` setters, which panic rather than silently overriding them — see
+    /// [`ComponentHooks::set_hook`](ComponentHooks).
+    pub fn register_component_hooks<T: Component>(&mut self) -> &mut ComponentHooks {
+        let id = self.register_component::<T>();
+        &mut self.infos[id.index()].hooks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct WithBakedInOnRemove;
+    impl Component for WithBakedInOnRemove {
+        type Storage = TableStorage;
+        fn init_component_info(info: &mut ComponentInfo) {
+            info.hooks_mut().on_remove(|_, _, _| {});
+        }
+    }
+
+    fn entity() -> Entity {
+        Entity::from_raw(0)
+    }
+
+    #[test]
+    fn set_hook_twice_panics() {
+        let mut hooks = ComponentHooks::default();
+        hooks.on_add(|_, _, _| {});
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            hooks.on_add(|_, _, _| {});
+        }));
+        assert!(
+            result.is_err(),
+            "a second `on_add` hook must be rejected, not silently overwrite the first"
+        );
+    }
+
+    #[test]
+    fn different_hook_kinds_can_coexist() {
+        let mut hooks = ComponentHooks::default();
+        hooks.on_add(|_, _, _| {});
+        hooks.on_insert(|_, _, _| {});
+        assert!(hooks.on_add.is_some());
+        assert!(hooks.on_insert.is_some());
+    }
+
+    #[test]
+    fn register_component_runs_init_component_info_once() {
+        let mut components = Components::default();
+        let id = components.register_component::<WithBakedInOnRemove>();
+        assert!(components.get_info(id).unwrap().hooks().on_remove.is_some());
+
+        // Registering again must not re-run `init_component_info` (it would panic the second
+        // time, since the baked-in `on_remove` would already be set).
+        let id_again = components.register_component::<WithBakedInOnRemove>();
+        assert_eq!(id, id_again);
+    }
+
+    #[test]
+    fn register_component_hooks_rejects_collision_with_baked_in_hook() {
+        let mut components = Components::default();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            components
+                .register_component_hooks::<WithBakedInOnRemove>()
+                .on_remove(|_, _, _| {});
+        }));
+        assert!(
+            result.is_err(),
+            "an app-level `on_remove` must be rejected once `init_component_info` already baked one in"
+        );
+    }
+
+    #[test]
+    fn register_component_hooks_composes_with_baked_in_hooks() {
+        let mut components = Components::default();
+        components
+            .register_component_hooks::<WithBakedInOnRemove>()
+            .on_add(|_, _, _| {});
+
+        let id = components.register_component::<WithBakedInOnRemove>();
+        let hooks = components.get_info(id).unwrap().hooks();
+        assert!(hooks.on_add.is_some(), "the app-level on_add should compose with the baked-in on_remove");
+        assert!(hooks.on_remove.is_some());
+    }
+
+    #[test]
+    fn merge_missing_from_keeps_already_set_hooks() {
+        let mut declared = ComponentHooks::default();
+        declared.on_add(|_, _, _| {});
+        declared.on_insert(|_, _, _| {});
+
+        let mut live = ComponentHooks::default();
+        let already_set = Arc::new(|_: DeferredWorld, _: Entity, _: ComponentId| {}) as ComponentHook;
+        live.on_add = Some(already_set.clone());
+
+        live.merge_missing_from(&declared);
+
+        assert!(
+            Arc::ptr_eq(live.on_add.as_ref().unwrap(), &already_set),
+            "merge_missing_from must not replace a hook slot that was already set"
+        );
+        assert!(
+            live.on_insert.is_some(),
+            "merge_missing_from must fill in a slot that wasn't already set"
+        );
+    }
+
+    #[test]
+    fn on_replace_runs_before_on_insert_and_on_add_does_not_run_alongside_it() {
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let mut hooks = ComponentHooks::default();
+        {
+            let order = order.clone();
+            hooks.on_add(move |_, _, _| order.lock().unwrap().push("on_add"));
+        }
+        {
+            let order = order.clone();
+            hooks.on_replace(move |_, _, _| order.lock().unwrap().push("on_replace"));
+        }
+        {
+            let order = order.clone();
+            hooks.on_insert(move |_, _, _| order.lock().unwrap().push("on_insert"));
+        }
+
+        // Simulate inserting onto an entity that already has the component: the insert codepath
+        // runs `run_on_replace`, swaps the value (nothing to do here), then `run_on_insert` —
+        // `run_on_add` is never called for a replace.
+        hooks.run_on_replace(DeferredWorld::default(), entity(), ComponentId::new(0));
+        hooks.run_on_insert(DeferredWorld::default(), entity(), ComponentId::new(0));
+
+        assert_eq!(*order.lock().unwrap(), vec!["on_replace", "on_insert"]);
+    }
+
+    #[test]
+    fn on_add_runs_before_on_insert_for_a_fresh_insert() {
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let mut hooks = ComponentHooks::default();
+        {
+            let order = order.clone();
+            hooks.on_add(move |_, _, _| order.lock().unwrap().push("on_add"));
+        }
+        {
+            let order = order.clone();
+            hooks.on_insert(move |_, _, _| order.lock().unwrap().push("on_insert"));
+        }
+
+        // Simulate inserting onto an entity that didn't already have the component: the insert
+        // codepath runs `run_on_add` (not `run_on_replace`), then `run_on_insert`.
+        hooks.run_on_add(DeferredWorld::default(), entity(), ComponentId::new(0));
+        hooks.run_on_insert(DeferredWorld::default(), entity(), ComponentId::new(0));
+
+        assert_eq!(*order.lock().unwrap(), vec!["on_add", "on_insert"]);
+    }
+}
@@ -0,0 +1,20 @@
+//! Entity identifiers.
+
+/// A lightweight identifier for an entity within a `World`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Entity {
+    index: u32,
+}
+
+impl Entity {
+    /// Creates an `Entity` from a raw index. Only `World`/`Entities` should call this outside of
+    /// tests, since nothing here guarantees the index is actually alive.
+    pub const fn from_raw(index: u32) -> Self {
+        Self { index }
+    }
+
+    /// The entity's raw index.
+    pub const fn index(self) -> u32 {
+        self.index
+    }
+}
@@ -0,0 +1,35 @@
+//! Reflection support for component-specific type data.
+
+use crate::component::{Component, ComponentHooks};
+use bevy_reflect::FromType;
+
+/// Type data that lets tooling (editors, scene loaders) install a component's declared lifecycle
+/// hooks purely from its `TypeRegistration`, without going through the concrete component type.
+///
+/// This is inserted automatically for any `#[derive(Reflect)]` type annotated with
+/// `#[reflect(Component)]`; see `impl_get_type_registration` in `bevy_reflect_derive`.
+#[derive(Clone, Default)]
+pub struct ReflectComponentHooks {
+    hooks: ComponentHooks,
+}
+
+impl ReflectComponentHooks {
+    /// Installs the hooks this component declared onto `hooks`, skipping any kind that's already
+    /// set. Unlike [`register_component_hooks`], which panics on a collision because it's an
+    /// explicit, one-off app-level call, this is meant to be safe to call repeatedly (e.g. once
+    /// per scene load) against hooks that may already be populated, so it silently keeps whatever
+    /// is already there instead.
+    ///
+    /// [`register_component_hooks`]: crate::world::World::register_component_hooks
+    pub fn install(&self, hooks: &mut ComponentHooks) {
+        hooks.merge_missing_from(&self.hooks);
+    }
+}
+
+impl<C: Component> FromType<C> for ReflectComponentHooks {
+    fn from_type() -> Self {
+        Self {
+            hooks: ComponentHooks::from_declared::<C>(),
+        }
+    }
+}
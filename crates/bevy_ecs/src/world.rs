@@ -0,0 +1,14 @@
+//! The `World` and the scoped views into it passed to component lifecycle hooks.
+
+/// A view into a `World` handed to a component lifecycle hook while it's mid-insert or mid-remove:
+/// it allows reading/writing components and resources and queuing commands, but not the kind of
+/// structural change (spawning/despawning, adding/removing components) that would invalidate the
+/// insert/remove already in progress.
+///
+/// This is a minimal stand-in sized only for what [`ComponentHooks`](crate::component::ComponentHooks)
+/// needs in order to invoke and test hooks; the full read/write surface (`get`, `resource_mut`,
+/// `commands`, ...) lives with the rest of `World`.
+#[derive(Default)]
+pub struct DeferredWorld {
+    _private: (),
+}
@@ -0,0 +1,112 @@
+//! Parsing for the `#[reflect(...)]` container attribute list.
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use std::collections::HashMap;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Ident, LitStr, Path, Token,
+};
+
+/// A single `#[reflect(...)]` entry: the type data to register, plus an optional
+/// `feature = "..."` predicate gating it, as in `#[reflect(Foo(feature = "editor"))]`.
+struct ReflectTraitEntry {
+    path: Path,
+    cfg: Option<TokenStream>,
+}
+
+impl Parse for ReflectTraitEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path: Path = input.parse()?;
+
+        let cfg = if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let key: Ident = content.parse()?;
+            if key != "feature" {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "expected `feature = \"...\"` inside `#[reflect(Trait(...))]`",
+                ));
+            }
+            content.parse::<Token![=]>()?;
+            let value: LitStr = content.parse()?;
+            Some(quote::quote!(feature = #value))
+        } else {
+            None
+        };
+
+        Ok(Self { path, cfg })
+    }
+}
+
+/// The parsed contents of a `#[reflect(...)]` attribute: the type data to register via
+/// `GetTypeRegistration`, each entry optionally gated behind a `cfg` predicate.
+#[derive(Default)]
+pub(crate) struct ReflectTraits {
+    idents: Vec<Path>,
+    cfgs: HashMap<String, TokenStream>,
+}
+
+impl ReflectTraits {
+    /// The type data paths requested for registration, in declaration order.
+    pub fn idents(&self) -> &[Path] {
+        &self.idents
+    }
+
+    /// The `cfg` predicate (if any) that should gate registering `ident`, as declared via
+    /// `#[reflect(Ident(feature = "..."))]`.
+    pub fn cfg_for(&self, ident: &Path) -> Option<TokenStream> {
+        self.cfgs.get(&path_key(ident)).cloned()
+    }
+}
+
+impl Parse for ReflectTraits {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let entries = Punctuated::<ReflectTraitEntry, Token![,]>::parse_terminated(input)?;
+        let mut traits = ReflectTraits::default();
+        for entry in entries {
+            if let Some(cfg) = entry.cfg {
+                traits.cfgs.insert(path_key(&entry.path), cfg);
+            }
+            traits.idents.push(entry.path);
+        }
+        Ok(traits)
+    }
+}
+
+fn path_key(path: &Path) -> String {
+    path.to_token_stream().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn idents_without_feature_have_no_cfg() {
+        let traits: ReflectTraits = parse_quote!(Component, Default);
+        let component: Path = parse_quote!(Component);
+        assert_eq!(traits.idents().len(), 2);
+        assert!(traits.cfg_for(&component).is_none());
+    }
+
+    #[test]
+    fn feature_predicate_is_recorded_per_ident() {
+        let traits: ReflectTraits = parse_quote!(Default, Component(feature = "bevy_ecs"));
+        let component: Path = parse_quote!(Component);
+        let default: Path = parse_quote!(Default);
+
+        let cfg = traits.cfg_for(&component).expect("Component should carry a cfg");
+        assert_eq!(cfg.to_string(), quote::quote!(feature = "bevy_ecs").to_string());
+        assert!(traits.cfg_for(&default).is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_key_inside_parens() {
+        let result: syn::Result<ReflectTraits> = syn::parse2(quote::quote!(Component(other = "x")));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,65 @@
+//! Parsed representation of a `#[derive(Reflect)]` input.
+
+use crate::container_attributes::ReflectTraits;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{DeriveInput, Generics, Ident, Path};
+
+/// Whether, and how, `FromReflect` should be auto-derived alongside `Reflect`.
+pub(crate) struct FromReflectAttrs {
+    auto_derive: bool,
+}
+
+impl FromReflectAttrs {
+    pub fn should_auto_derive(&self) -> bool {
+        self.auto_derive
+    }
+}
+
+/// The name and generics of the type being derived, as needed to write `impl ... for Self`.
+pub(crate) struct ReflectTypePath<'a> {
+    ident: &'a Ident,
+    generics: &'a Generics,
+}
+
+impl<'a> ReflectTypePath<'a> {
+    pub fn generics(&self) -> &'a Generics {
+        self.generics
+    }
+}
+
+impl ToTokens for ReflectTypePath<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.ident.to_tokens(tokens);
+    }
+}
+
+/// Parsed `#[derive(Reflect)]` input: the type itself, its `#[reflect(...)]` container
+/// attributes, and whether `FromReflect` should be auto-derived alongside it.
+pub(crate) struct ReflectMeta {
+    input: DeriveInput,
+    traits: ReflectTraits,
+    from_reflect: FromReflectAttrs,
+    bevy_reflect_path: Path,
+}
+
+impl ReflectMeta {
+    pub fn type_path(&self) -> ReflectTypePath<'_> {
+        ReflectTypePath {
+            ident: &self.input.ident,
+            generics: &self.input.generics,
+        }
+    }
+
+    pub fn bevy_reflect_path(&self) -> &Path {
+        &self.bevy_reflect_path
+    }
+
+    pub fn attrs(&self) -> &ReflectTraits {
+        &self.traits
+    }
+
+    pub fn from_reflect(&self) -> &FromReflectAttrs {
+        &self.from_reflect
+    }
+}
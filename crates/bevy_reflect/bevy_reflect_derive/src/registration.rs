@@ -33,6 +33,50 @@ pub(crate) fn impl_get_type_registration(
         }
     });
 
+    // `ReflectComponentHooks` lets tooling install a component's declared lifecycle hooks
+    // purely from its `TypeRegistration`, so it's only emitted for types that also register
+    // `ReflectComponent` (i.e. are annotated with `#[reflect(Component)]`). It lives in
+    // `bevy_ecs`, not `bevy_reflect`, for the same reason `ReflectComponent` itself does: a
+    // component's hooks need ECS types (`Entity`, `DeferredWorld`) that `bevy_reflect` can't
+    // depend on.
+    let component_path = registration_data
+        .iter()
+        .find(|path| path.segments.last().is_some_and(|segment| segment.ident == "Component"));
+    let component_hooks_data = component_path.map(|component_path| {
+        let insert = quote! {
+            registration.insert::<::bevy_ecs::reflect::ReflectComponentHooks>(#bevy_reflect_path::FromType::<Self>::from_type());
+        };
+
+        // `Component`'s own cfg (e.g. from `#[reflect(Component(feature = "..."))]`) also gates
+        // `ReflectComponentHooks`: it names the same ECS type data that `#[reflect(Component)]`
+        // itself is gated behind, so it can't be registered when `ReflectComponent` isn't.
+        match meta.attrs().cfg_for(component_path) {
+            Some(cfg) => quote! {
+                #[cfg(#cfg)]
+                #insert
+            },
+            None => insert,
+        }
+    });
+
+    // Each registered ident can carry an optional `#[reflect(Foo(feature = "..."))]` predicate,
+    // in which case the `registration.insert` call for that ident is gated behind a matching
+    // `#[cfg(...)]` so the corresponding type data doesn't need to compile when the feature (or
+    // other cfg-gated subsystem) it belongs to is disabled.
+    let registration_data_defs = registration_data.iter().map(|ident| {
+        let insert = quote! {
+            registration.insert::<#ident>(#bevy_reflect_path::FromType::<Self>::from_type());
+        };
+
+        match meta.attrs().cfg_for(ident) {
+            Some(cfg) => quote! {
+                #[cfg(#cfg)]
+                #insert
+            },
+            None => insert,
+        }
+    });
+
     quote! {
         #[allow(unused_mut)]
         impl #impl_generics #bevy_reflect_path::GetTypeRegistration for #type_path #ty_generics #where_reflect_clause {
@@ -41,7 +85,8 @@ pub(crate) fn impl_get_type_registration(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
-                #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
+                #component_hooks_data
+                #(#registration_data_defs)*
                 registration
             }
         }
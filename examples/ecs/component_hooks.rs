@@ -10,10 +10,22 @@ struct MyComponent(KeyCode);
 impl Component for MyComponent {
     type Storage = TableStorage;
 
-    /// Hooks can also be registered during component initialisation by
-    /// implementing `init_component_info`
-    fn init_component_info(_info: &mut ComponentInfo) {
-        // Register hooks...
+    /// Hooks can also be baked into the type itself by implementing `init_component_info`.
+    /// These run the first time the component is registered, before any archetype containing it
+    /// is created, so they're always in place by the time `MyComponent` is actually used. This
+    /// is the place to put hooks you want every app using `MyComponent` to get for free, without
+    /// relying on a `setup` system to call `register_component_hooks`. An app-level call to
+    /// `register_component_hooks` is still rejected if it tries to set a hook kind that's
+    /// already baked in here, same as it's rejected against a hook set by a plugin
+    fn init_component_info(info: &mut ComponentInfo) {
+        info.hooks_mut().on_remove(|mut world, entity, component_id| {
+            let value = world.get::<MyComponent>(entity).unwrap().0;
+            println!(
+                "Component: {:?} removed from: {:?} with value {:?}",
+                component_id, entity, value
+            );
+            world.resource_mut::<MyComponentIndex>().remove(&value);
+        });
     }
 }
 
@@ -40,7 +52,7 @@ fn setup(world: &mut World) {
     // This is to prevent overriding hooks defined in plugins and other crates as well as keeping things fast
     world
         .register_component_hooks::<MyComponent>()
-        // There are 3 component lifecycle hooks: `on_add`, `on_insert` and `on_remove`
+        // There are 4 component lifecycle hooks: `on_add`, `on_insert`, `on_replace` and `on_remove`
         // A hook has 3 arguments:
         // - a `DeferredWorld`, this allows access to resource and component data as well as `Commands`
         // - the entity that triggered the hook
@@ -61,23 +73,26 @@ fn setup(world: &mut World) {
             // Or send events
             world.send_event(MyEvent);
         })
+        // `on_replace` will trigger when a component is inserted onto an entity that already
+        // has it, running before the value is overwritten. This is the only chance to read the
+        // outgoing value, which is why it's used here to keep `MyComponentIndex` from going
+        // stale when a `MyComponent` is replaced with a new `KeyCode` instead of being removed
+        // and re-added
+        .on_replace(|mut world, entity, _| {
+            let value = world.get::<MyComponent>(entity).unwrap().0;
+            world.resource_mut::<MyComponentIndex>().remove(&value);
+        })
         // `on_insert` will trigger when a component is inserted onto an entity,
         // regardless of whether or not it already had it and after `on_add` if it ran
-        .on_insert(|world, _, _| {
-            println!("Current Index: {:?}", world.resource::<MyComponentIndex>());
-        })
-        // `on_remove` will trigger when a component is removed from an entity,
-        // since it runs before the component is removed you can still access the component data
-        .on_remove(|mut world, entity, component_id| {
+        .on_insert(|mut world, entity, _| {
             let value = world.get::<MyComponent>(entity).unwrap().0;
-            println!(
-                "Component: {:?} removed from: {:?} with value {:?}",
-                component_id, entity, value
-            );
-            world.resource_mut::<MyComponentIndex>().remove(&value);
-            // You can also issue commands through `.commands()`
-            world.commands().entity(entity).despawn();
+            world
+                .resource_mut::<MyComponentIndex>()
+                .insert(value, entity);
+            println!("Current Index: {:?}", world.resource::<MyComponentIndex>());
         });
+    // `on_remove` isn't registered here: `MyComponent::init_component_info` already bakes one in,
+    // and trying to register another of the same kind on top of it would panic
 }
 
 fn trigger_hooks(
@@ -87,7 +102,10 @@ fn trigger_hooks(
 ) {
     for (key, entity) in index.iter() {
         if !keys.pressed(*key) {
+            // Removing `MyComponent` triggers its baked-in `on_remove` hook, then we despawn the
+            // now-empty entity
             commands.entity(*entity).remove::<MyComponent>();
+            commands.entity(*entity).despawn();
         }
     }
     for key in keys.get_just_pressed() {